@@ -0,0 +1,150 @@
+//! RISC-V run-time detectable features.
+//!
+//! This is the `Feature` enum consumed throughout `crate::detect::os::riscv`
+//! (and its OS-specific providers): each variant is an index into the
+//! run-time feature detection cache bitset, so the discriminant of a variant
+//! must never change once it ships.
+
+/// A RISC-V extension (or runtime pseudo-feature derived from one, such as a
+/// performance hint) that can be detected at run time.
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum Feature {
+    /// `RV32I`: Base integer instruction set, 32-bit.
+    rv32i,
+    /// `RV32E`: Base integer instruction set, 32-bit, embedded (16 registers).
+    rv32e,
+    /// `RV64I`: Base integer instruction set, 64-bit.
+    rv64i,
+
+    /// `A`: Atomic instructions.
+    a,
+    /// `B`: Bit-manipulation (`Zba` & `Zbb` & `Zbs`).
+    b,
+    /// `C`: Compressed instructions.
+    c,
+    /// `D`: Double-precision floating point.
+    d,
+    /// `F`: Single-precision floating point.
+    f,
+    /// `H`: Hypervisor.
+    h,
+    /// `M`: Integer multiplication and division.
+    m,
+    /// `Q`: Quad-precision floating point.
+    q,
+    /// `S`: Supervisor mode.
+    s,
+    /// `V`: Vector extension.
+    v,
+
+    /// `Zicsr`: Control and status register instructions.
+    zicsr,
+    /// `Zicntr`: Base counters and timers.
+    zicntr,
+    /// `Zihpm`: Hardware performance counters.
+    zihpm,
+    /// `Zicboz`: Cache-block zero instructions.
+    zicboz,
+    /// `Zihintntl`: Non-temporal locality hints.
+    zihintntl,
+
+    /// `Zalrsc`: Load-reserved/store-conditional instructions.
+    zalrsc,
+    /// `Zaamo`: Atomic memory operation instructions.
+    zaamo,
+
+    /// `Zba`: Address generation bit-manipulation.
+    zba,
+    /// `Zbb`: Basic bit-manipulation.
+    zbb,
+    /// `Zbc`: Carry-less multiplication.
+    zbc,
+    /// `Zbs`: Single-bit bit-manipulation.
+    zbs,
+
+    /// `Zfh`: Half-precision floating point.
+    zfh,
+    /// `Zfhmin`: Minimal half-precision floating point.
+    zfhmin,
+    /// `Zfinx`: Single-precision floating point in integer registers.
+    zfinx,
+    /// `Zdinx`: Double-precision floating point in integer registers.
+    zdinx,
+    /// `Zhinx`: Half-precision floating point in integer registers.
+    zhinx,
+    /// `Zhinxmin`: Minimal half-precision floating point in integer registers.
+    zhinxmin,
+
+    /// `Zbkb`: Bit-manipulation instructions for cryptography.
+    zbkb,
+    /// `Zbkc`: Carry-less multiplication for cryptography.
+    zbkc,
+    /// `Zbkx`: Crossbar permutation instructions for cryptography.
+    zbkx,
+    /// `Zknd`: NIST suite: AES decryption.
+    zknd,
+    /// `Zkne`: NIST suite: AES encryption.
+    zkne,
+    /// `Zknh`: NIST suite: hash functions.
+    zknh,
+    /// `Zksed`: ShangMi suite: SM4 block cipher.
+    zksed,
+    /// `Zksh`: ShangMi suite: SM3 hash function.
+    zksh,
+    /// `Zkr`: Entropy source extension.
+    zkr,
+    /// `Zkt`: Data-independent execution latency.
+    zkt,
+    /// `Zkn`: NIST algorithm suite (`Zbkb` & `Zbkc` & `Zbkx` & `Zkne` & `Zknd` & `Zknh`).
+    zkn,
+    /// `Zks`: ShangMi algorithm suite (`Zbkb` & `Zbkc` & `Zbkx` & `Zksed` & `Zksh`).
+    zks,
+    /// `Zk`: Scalar cryptography suite (`Zkn` & `Zkr` & `Zkt`).
+    zk,
+
+    /// `Zvbb`: Vector basic bit-manipulation.
+    zvbb,
+
+    /// `Zve32x`: Vector extension, embedded profile, 32-bit integer only.
+    zve32x,
+    /// `Zve32f`: `Zve32x` plus single-precision floating point.
+    zve32f,
+    /// `Zve64x`: Vector extension, embedded profile, 64-bit integer.
+    zve64x,
+    /// `Zve64f`: `Zve64x` plus single-precision floating point.
+    zve64f,
+    /// `Zve64d`: `Zve64f` plus double-precision floating point.
+    zve64d,
+
+    /// `Zvl32b`: Minimum vector length of 32 bits.
+    zvl32b,
+    /// `Zvl64b`: Minimum vector length of 64 bits.
+    zvl64b,
+    /// `Zvl128b`: Minimum vector length of 128 bits.
+    zvl128b,
+    /// `Zvl256b`: Minimum vector length of 256 bits.
+    zvl256b,
+    /// `Zvl512b`: Minimum vector length of 512 bits.
+    zvl512b,
+    /// `Zvl1024b`: Minimum vector length of 1024 bits.
+    zvl1024b,
+    /// `Zvl2048b`: Minimum vector length of 2048 bits.
+    zvl2048b,
+    /// `Zvl4096b`: Minimum vector length of 4096 bits.
+    zvl4096b,
+    /// `Zvl8192b`: Minimum vector length of 8192 bits.
+    zvl8192b,
+    /// `Zvl16384b`: Minimum vector length of 16384 bits.
+    zvl16384b,
+    /// `Zvl32768b`: Minimum vector length of 32768 bits.
+    zvl32768b,
+    /// `Zvl65536b`: Minimum vector length of 65536 bits.
+    zvl65536b,
+
+    /// Not an ISA extension: set when `riscv_hwprobe` reports that
+    /// misaligned scalar accesses run at full (`FAST`) speed on every
+    /// probed CPU, so that code can choose a vectorized/word-wise path
+    /// over a byte-wise one.
+    fast_unaligned_access,
+}
@@ -55,6 +55,32 @@ pub(crate) fn imply_features(mut value: cache::Initializer) -> cache::Initialize
 
         group!(b == zba & zbb & zbs);
 
+        // Vector extension dependency graph. Note that this is deliberately
+        // one-directional: `v` implies its Zve* subsets, but not vice versa,
+        // since e.g. Zve64* omits some 64-bit integer-multiply-high forms
+        // that full `v` provides. This matches how LLVM treats Zve* as
+        // distinct subtargets rather than as mere aliases of `v`.
+        imply!(v => zve64d & zvl128b);
+        imply!(zve64d => zve64f & d);
+        imply!(zve64f => zve32f & zve64x);
+        imply!(zve32f => zve32x & f);
+        imply!(zve64x => zve32x & zvl64b);
+        imply!(zve32x => zvl32b);
+
+        // VLEN doubling chain: a minimum vector length also satisfies every
+        // smaller minimum vector length.
+        imply!(zvl65536b => zvl32768b);
+        imply!(zvl32768b => zvl16384b);
+        imply!(zvl16384b => zvl8192b);
+        imply!(zvl8192b => zvl4096b);
+        imply!(zvl4096b => zvl2048b);
+        imply!(zvl2048b => zvl1024b);
+        imply!(zvl1024b => zvl512b);
+        imply!(zvl512b => zvl256b);
+        imply!(zvl256b => zvl128b);
+        imply!(zvl128b => zvl64b);
+        imply!(zvl64b => zvl32b);
+
         imply!(zhinx => zhinxmin);
         imply!(zdinx | zhinxmin => zfinx);
 
@@ -72,6 +98,459 @@ pub(crate) fn imply_features(mut value: cache::Initializer) -> cache::Initialize
     }
 }
 
+/// Key/value pairs exchanged with the kernel through the `riscv_hwprobe` syscall.
+///
+/// This mirrors `struct riscv_hwprobe` from the kernel's
+/// [`uapi/asm/hwprobe.h`][hwprobe].
+///
+/// [hwprobe]: https://git.kernel.org/pub/scm/linux/kernel/git/torvalds/linux.git/tree/arch/riscv/include/uapi/asm/hwprobe.h?h=v6.14
+#[repr(C)]
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, Debug, Default)]
+struct riscv_hwprobe {
+    key: i64,
+    value: u64,
+}
+
+// Keys recognized by `RISCV_HWPROBE_KEY_*`.
+const RISCV_HWPROBE_KEY_BASE_BEHAVIOR: i64 = 3;
+const RISCV_HWPROBE_BASE_BEHAVIOR_IMA: u64 = 1 << 0;
+
+const RISCV_HWPROBE_KEY_IMA_EXT_0: i64 = 4;
+const RISCV_HWPROBE_IMA_FD: u64 = 1 << 0;
+const RISCV_HWPROBE_IMA_C: u64 = 1 << 1;
+const RISCV_HWPROBE_IMA_V: u64 = 1 << 2;
+const RISCV_HWPROBE_EXT_ZBA: u64 = 1 << 3;
+const RISCV_HWPROBE_EXT_ZBB: u64 = 1 << 4;
+const RISCV_HWPROBE_EXT_ZBS: u64 = 1 << 5;
+const RISCV_HWPROBE_EXT_ZICBOZ: u64 = 1 << 6;
+const RISCV_HWPROBE_EXT_ZBC: u64 = 1 << 7;
+const RISCV_HWPROBE_EXT_ZBKB: u64 = 1 << 8;
+const RISCV_HWPROBE_EXT_ZBKC: u64 = 1 << 9;
+const RISCV_HWPROBE_EXT_ZBKX: u64 = 1 << 10;
+const RISCV_HWPROBE_EXT_ZKND: u64 = 1 << 11;
+const RISCV_HWPROBE_EXT_ZKNE: u64 = 1 << 12;
+const RISCV_HWPROBE_EXT_ZKNH: u64 = 1 << 13;
+const RISCV_HWPROBE_EXT_ZKSED: u64 = 1 << 14;
+const RISCV_HWPROBE_EXT_ZKSH: u64 = 1 << 15;
+const RISCV_HWPROBE_EXT_ZKR: u64 = 1 << 16;
+const RISCV_HWPROBE_EXT_ZKT: u64 = 1 << 17;
+const RISCV_HWPROBE_EXT_ZIHINTNTL: u64 = 1 << 18;
+const RISCV_HWPROBE_EXT_ZVBB: u64 = 1 << 19;
+
+// `RISCV_HWPROBE_KEY_MISALIGNED_SCALAR_PERF` is the current name; older
+// kernels only know its predecessor, `RISCV_HWPROBE_KEY_MISALIGNED_MEMORY_ACCESS`.
+// Both report the same `RISCV_HWPROBE_MISALIGNED_*` value enum.
+const RISCV_HWPROBE_KEY_MISALIGNED_MEMORY_ACCESS: i64 = 8;
+const RISCV_HWPROBE_KEY_MISALIGNED_SCALAR_PERF: i64 = 9;
+const RISCV_HWPROBE_MISALIGNED_UNKNOWN: u64 = 0;
+#[allow(dead_code)] // part of the kernel's enum; not otherwise distinguished below
+const RISCV_HWPROBE_MISALIGNED_EMULATED: u64 = 1;
+#[allow(dead_code)]
+const RISCV_HWPROBE_MISALIGNED_SLOW: u64 = 2;
+const RISCV_HWPROBE_MISALIGNED_FAST: u64 = 3;
+#[allow(dead_code)]
+const RISCV_HWPROBE_MISALIGNED_UNSUPPORTED: u64 = 4;
+
+/// A key that the kernel did not recognize comes back with its `key` field
+/// overwritten with `-1` and an unspecified `value`; see the `riscv_hwprobe`
+/// manual page.
+const RISCV_HWPROBE_KEY_UNKNOWN: i64 = -1;
+
+/// `__NR_riscv_hwprobe`, as defined in the kernel's
+/// `arch/riscv/include/uapi/asm/unistd.h`.
+const SYS_RISCV_HWPROBE: usize = 258;
+
+/// `ENOSYS`, as defined in the kernel's `include/uapi/asm-generic/errno.h`.
+const ENOSYS: i64 = 38;
+
+/// Signature of both `__vdso_riscv_hwprobe` and the raw syscall: `pairs`/
+/// `pair_count` is the probe array, `cpu_count`/`cpus` select which CPUs to
+/// probe (`0`/`NULL` meaning "any online CPU, answer must hold for all of
+/// them"), and `flags` is reserved and must be zero.
+type HwprobeFn = unsafe extern "C" fn(*mut riscv_hwprobe, usize, usize, *mut usize, usize) -> i64;
+
+/// Issue the `riscv_hwprobe` request with the given probe pairs, preferring
+/// the `__vdso_riscv_hwprobe` entry point (which avoids the cost of a trap
+/// into the kernel) and falling back to the raw syscall when no vDSO symbol
+/// is available.
+///
+/// Returns `false` if the probe could not be completed. In practice this
+/// should only happen on kernels old enough to lack `riscv_hwprobe`
+/// entirely (`ENOSYS`, i.e. Linux older than 6.4), in which case the caller
+/// should fall back to whatever auxvec-based detection already produced
+/// instead of treating this as a hard failure. Any other error means the
+/// probe itself was misconstructed rather than merely unsupported, so it
+/// trips a `debug_assert!` instead of silently degrading like `ENOSYS` does.
+fn hwprobe(probes: &mut [riscv_hwprobe]) -> bool {
+    let ret = if let Some(vdso_hwprobe) = vdso_hwprobe() {
+        unsafe { vdso_hwprobe(probes.as_mut_ptr(), probes.len(), 0, core::ptr::null_mut(), 0) }
+    } else {
+        unsafe { syscall_riscv_hwprobe(probes.as_mut_ptr(), probes.len()) }
+    };
+    if ret == 0 {
+        return true;
+    }
+    debug_assert_eq!(
+        ret,
+        -ENOSYS,
+        "riscv_hwprobe failed unexpectedly (errno {}); expected ENOSYS on kernels that lack it",
+        -ret
+    );
+    false
+}
+
+/// Issue the raw `riscv_hwprobe` syscall. See [`HwprobeFn`] for the argument
+/// layout, which the syscall and the vDSO entry point share.
+unsafe fn syscall_riscv_hwprobe(pairs: *mut riscv_hwprobe, pair_count: usize) -> i64 {
+    let ret: isize;
+    unsafe {
+        core::arch::asm!(
+            "ecall",
+            in("a7") SYS_RISCV_HWPROBE,
+            inlateout("a0") pairs => ret,
+            in("a1") pair_count,
+            in("a2") 0usize,
+            in("a3") 0usize,
+            in("a4") 0usize,
+        );
+    }
+    ret as i64
+}
+
+/// Resolve `__vdso_riscv_hwprobe` from the vDSO the kernel mapped into this
+/// process, caching the result (including a negative one) across calls
+/// since resolving it means walking an ELF image.
+///
+/// Returns `None` on kernels whose vDSO does not export the symbol (old
+/// kernels, or builds without `riscv_hwprobe` support), in which case the
+/// caller should fall back to the raw syscall.
+#[cfg(target_arch = "riscv64")]
+fn vdso_hwprobe() -> Option<HwprobeFn> {
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    // 0 = not yet resolved, 1 = resolved to "no such symbol"; anything else
+    // is the resolved function's address.
+    const UNRESOLVED: usize = 0;
+    const NOT_AVAILABLE: usize = 1;
+    static CACHE: AtomicUsize = AtomicUsize::new(UNRESOLVED);
+
+    let cached = CACHE.load(Ordering::Relaxed);
+    let addr = if cached == UNRESOLVED {
+        let resolved = vdso::sysinfo_ehdr()
+            .and_then(|ehdr| unsafe { vdso::resolve_symbol(ehdr, b"__vdso_riscv_hwprobe") })
+            .unwrap_or(NOT_AVAILABLE);
+        // A racing writer can only compute the same answer from the same
+        // read-only vDSO image, so a relaxed store is enough.
+        CACHE.store(resolved, Ordering::Relaxed);
+        resolved
+    } else {
+        cached
+    };
+
+    (addr != NOT_AVAILABLE).then(|| unsafe { core::mem::transmute::<usize, HwprobeFn>(addr) })
+}
+
+/// RV32's vDSO uses the 32-bit ELF class, which [`vdso::resolve_symbol`]
+/// does not parse (see that module's doc comment for the scope decision);
+/// always fall back to the raw syscall here rather than attempt a wrong or
+/// partial 32-bit ELF walk.
+#[cfg(not(target_arch = "riscv64"))]
+fn vdso_hwprobe() -> Option<HwprobeFn> {
+    None
+}
+
+/// Minimal vDSO symbol resolution, just enough to go from `AT_SYSINFO_EHDR`
+/// to a `__vdso_riscv_hwprobe` function pointer.
+///
+/// This is not a general-purpose ELF loader: it understands only the
+/// 64-bit ELF layout RV64's vDSO uses, and only the legacy SysV hash
+/// section (`DT_HASH`) for bounding the dynamic symbol table. A vDSO that
+/// publishes only `DT_GNU_HASH` is treated the same as "vDSO unavailable";
+/// in practice this doesn't come up, since glibc-oriented vDSOs keep
+/// `DT_HASH` around for compatibility.
+#[cfg(target_arch = "riscv64")]
+mod vdso {
+    const AT_NULL: u64 = 0;
+    const AT_SYSINFO_EHDR: u64 = 33;
+
+    const EI_CLASS: usize = 4;
+    const ELFCLASS64: u8 = 2;
+
+    const PT_LOAD: u32 = 1;
+    const PT_DYNAMIC: u32 = 2;
+
+    const DT_NULL: u64 = 0;
+    const DT_HASH: u64 = 4;
+    const DT_STRTAB: u64 = 5;
+    const DT_SYMTAB: u64 = 6;
+
+    unsafe fn read_u16(base: usize, offset: usize) -> u16 {
+        unsafe { (base as *const u8).add(offset).cast::<u16>().read_unaligned() }
+    }
+    unsafe fn read_u32(base: usize, offset: usize) -> u32 {
+        unsafe { (base as *const u8).add(offset).cast::<u32>().read_unaligned() }
+    }
+    unsafe fn read_u64(base: usize, offset: usize) -> u64 {
+        unsafe { (base as *const u8).add(offset).cast::<u64>().read_unaligned() }
+    }
+
+    /// Find the `AT_SYSINFO_EHDR` entry (the vDSO's base address) by
+    /// scanning `/proc/self/auxv`, read through the same raw-syscall
+    /// approach `hwprobe` itself uses rather than through `libc`.
+    pub(super) fn sysinfo_ehdr() -> Option<usize> {
+        const AT_FDCWD: isize = -100;
+        const O_RDONLY: usize = 0;
+        const SYS_OPENAT: usize = 56;
+        const SYS_READ: usize = 63;
+        const SYS_CLOSE: usize = 57;
+        const PATH: &[u8] = b"/proc/self/auxv\0";
+
+        unsafe fn syscall4(nr: usize, a0: usize, a1: usize, a2: usize, a3: usize) -> isize {
+            let ret: isize;
+            unsafe {
+                core::arch::asm!(
+                    "ecall",
+                    in("a7") nr,
+                    inlateout("a0") a0 => ret,
+                    in("a1") a1,
+                    in("a2") a2,
+                    in("a3") a3,
+                );
+            }
+            ret
+        }
+
+        let fd = unsafe {
+            syscall4(
+                SYS_OPENAT,
+                AT_FDCWD as usize,
+                PATH.as_ptr() as usize,
+                O_RDONLY,
+                0,
+            )
+        };
+        if fd < 0 {
+            return None;
+        }
+        let fd = fd as usize;
+
+        // The auxiliary vector is a short list of `(u64, u64)` pairs; this
+        // comfortably covers every entry the kernel defines today.
+        let mut buf = [0u8; 512];
+        let mut filled = 0usize;
+        while filled < buf.len() {
+            let n = unsafe {
+                syscall4(
+                    SYS_READ,
+                    fd,
+                    buf.as_mut_ptr() as usize + filled,
+                    buf.len() - filled,
+                    0,
+                )
+            };
+            if n <= 0 {
+                break;
+            }
+            filled += n as usize;
+        }
+        unsafe {
+            syscall4(SYS_CLOSE, fd, 0, 0, 0);
+        }
+
+        let entries = filled / 16;
+        for i in 0..entries {
+            let off = i * 16;
+            let a_type = u64::from_ne_bytes(buf[off..off + 8].try_into().unwrap());
+            let a_val = u64::from_ne_bytes(buf[off + 8..off + 16].try_into().unwrap());
+            if a_type == AT_SYSINFO_EHDR {
+                return Some(a_val as usize);
+            }
+            if a_type == AT_NULL {
+                break;
+            }
+        }
+        None
+    }
+
+    /// Resolve `name` to its runtime address within the vDSO ELF image
+    /// mapped at `ehdr_addr`.
+    ///
+    /// # Safety
+    ///
+    /// `ehdr_addr` must be the address of a valid ELF image mapped into
+    /// this process, as the kernel guarantees for `AT_SYSINFO_EHDR`.
+    pub(super) unsafe fn resolve_symbol(ehdr_addr: usize, name: &[u8]) -> Option<usize> {
+        // SAFETY: the caller guarantees `ehdr_addr` points at a mapped ELF
+        // image; its first 16 bytes (`e_ident`) are always present.
+        let ident = unsafe { core::slice::from_raw_parts(ehdr_addr as *const u8, 16) };
+        if ident[0..4] != [0x7f, b'E', b'L', b'F'] || ident[EI_CLASS] != ELFCLASS64 {
+            return None;
+        }
+
+        // SAFETY: `ehdr_addr` points at a valid `Elf64_Ehdr`; these fields
+        // are all within its fixed-size, unconditionally present header.
+        let e_phoff = unsafe { read_u64(ehdr_addr, 32) } as usize;
+        let e_phentsize = unsafe { read_u16(ehdr_addr, 54) } as usize;
+        let e_phnum = unsafe { read_u16(ehdr_addr, 56) } as usize;
+
+        let mut load_bias = None;
+        let mut dyn_vaddr = None;
+        for i in 0..e_phnum {
+            let ph = ehdr_addr + e_phoff + i * e_phentsize;
+            // SAFETY: within the program header table just located above.
+            let p_type = unsafe { read_u32(ph, 0) };
+            let p_vaddr = unsafe { read_u64(ph, 16) };
+            if p_type == PT_LOAD && load_bias.is_none() {
+                load_bias = Some(ehdr_addr.wrapping_sub(p_vaddr as usize));
+            }
+            if p_type == PT_DYNAMIC {
+                dyn_vaddr = Some(p_vaddr);
+            }
+        }
+        let load_bias = load_bias?;
+        let dyn_addr = load_bias.wrapping_add(dyn_vaddr? as usize);
+
+        let mut hash_addr = None;
+        let mut strtab_addr = None;
+        let mut symtab_addr = None;
+        // A `DT_NULL`-terminated list; bounded defensively in case a
+        // malformed image never terminates it.
+        for i in 0..4096 {
+            let entry = dyn_addr + i * 16;
+            // SAFETY: part of the `PT_DYNAMIC` segment located above.
+            let d_tag = unsafe { read_u64(entry, 0) };
+            let d_val = unsafe { read_u64(entry, 8) };
+            match d_tag {
+                DT_NULL => break,
+                DT_HASH => hash_addr = Some(load_bias.wrapping_add(d_val as usize)),
+                DT_STRTAB => strtab_addr = Some(load_bias.wrapping_add(d_val as usize)),
+                DT_SYMTAB => symtab_addr = Some(load_bias.wrapping_add(d_val as usize)),
+                _ => {}
+            }
+        }
+        let hash_addr = hash_addr?;
+        let strtab_addr = strtab_addr?;
+        let symtab_addr = symtab_addr?;
+
+        // `DT_HASH`'s SysV hash table opens with `nbucket` then `nchain`;
+        // `nchain` equals the symbol table's entry count, which is the one
+        // detail this minimal walk needs from it — it does not otherwise
+        // use the hash table to do the lookup.
+        // SAFETY: `hash_addr` points at a `DT_HASH` section.
+        let nchain = unsafe { read_u32(hash_addr, 4) } as usize;
+
+        for idx in 1..nchain {
+            let sym = symtab_addr + idx * 24;
+            // SAFETY: within the symbol table, bounded by `nchain` above.
+            let st_name = unsafe { read_u32(sym, 0) } as usize;
+            if st_name == 0 {
+                continue;
+            }
+            // SAFETY: `st_name` is a byte offset into `DT_STRTAB`, which
+            // per the ELF spec points at a NUL-terminated string.
+            let matches = unsafe {
+                let mut p = (strtab_addr + st_name) as *const u8;
+                let mut matched = true;
+                for &b in name {
+                    if p.read() != b {
+                        matched = false;
+                        break;
+                    }
+                    p = p.add(1);
+                }
+                matched && p.read() == 0
+            };
+            if matches {
+                // SAFETY: within the symbol table, as above.
+                let st_value = unsafe { read_u64(sym, 8) };
+                return Some(load_bias.wrapping_add(st_value as usize));
+            }
+        }
+        None
+    }
+}
+
+/// Probe the `riscv_hwprobe` syscall for the multi-letter extensions that
+/// the auxiliary vector cannot report, setting the corresponding bits in
+/// `value`. Returns `false` on kernels that do not implement the syscall
+/// at all, in which case `value` is left untouched and the caller should
+/// rely on auxvec-only detection instead.
+fn detect_features_hwprobe(value: &mut cache::Initializer) -> bool {
+    let mut probes = [
+        riscv_hwprobe {
+            key: RISCV_HWPROBE_KEY_BASE_BEHAVIOR,
+            value: 0,
+        },
+        riscv_hwprobe {
+            key: RISCV_HWPROBE_KEY_IMA_EXT_0,
+            value: 0,
+        },
+        riscv_hwprobe {
+            key: RISCV_HWPROBE_KEY_MISALIGNED_SCALAR_PERF,
+            value: 0,
+        },
+        riscv_hwprobe {
+            key: RISCV_HWPROBE_KEY_MISALIGNED_MEMORY_ACCESS,
+            value: 0,
+        },
+    ];
+    if !hwprobe(&mut probes) {
+        return false;
+    }
+
+    let mut enable_feature = |feature, enable| {
+        if enable {
+            value.set(feature as u32);
+        }
+    };
+
+    let base_behavior = probes[0].value;
+    enable_feature(
+        Feature::rv64i,
+        cfg!(target_arch = "riscv64") && (base_behavior & RISCV_HWPROBE_BASE_BEHAVIOR_IMA) != 0,
+    );
+
+    let ext0 = probes[1].value;
+    enable_feature(Feature::d, ext0 & RISCV_HWPROBE_IMA_FD != 0);
+    enable_feature(Feature::f, ext0 & RISCV_HWPROBE_IMA_FD != 0);
+    enable_feature(Feature::c, ext0 & RISCV_HWPROBE_IMA_C != 0);
+    enable_feature(Feature::v, ext0 & RISCV_HWPROBE_IMA_V != 0);
+    enable_feature(Feature::zba, ext0 & RISCV_HWPROBE_EXT_ZBA != 0);
+    enable_feature(Feature::zbb, ext0 & RISCV_HWPROBE_EXT_ZBB != 0);
+    enable_feature(Feature::zbs, ext0 & RISCV_HWPROBE_EXT_ZBS != 0);
+    enable_feature(Feature::zicboz, ext0 & RISCV_HWPROBE_EXT_ZICBOZ != 0);
+    enable_feature(Feature::zbc, ext0 & RISCV_HWPROBE_EXT_ZBC != 0);
+    enable_feature(Feature::zbkb, ext0 & RISCV_HWPROBE_EXT_ZBKB != 0);
+    enable_feature(Feature::zbkc, ext0 & RISCV_HWPROBE_EXT_ZBKC != 0);
+    enable_feature(Feature::zbkx, ext0 & RISCV_HWPROBE_EXT_ZBKX != 0);
+    enable_feature(Feature::zknd, ext0 & RISCV_HWPROBE_EXT_ZKND != 0);
+    enable_feature(Feature::zkne, ext0 & RISCV_HWPROBE_EXT_ZKNE != 0);
+    enable_feature(Feature::zknh, ext0 & RISCV_HWPROBE_EXT_ZKNH != 0);
+    enable_feature(Feature::zksed, ext0 & RISCV_HWPROBE_EXT_ZKSED != 0);
+    enable_feature(Feature::zksh, ext0 & RISCV_HWPROBE_EXT_ZKSH != 0);
+    enable_feature(Feature::zkr, ext0 & RISCV_HWPROBE_EXT_ZKR != 0);
+    enable_feature(Feature::zkt, ext0 & RISCV_HWPROBE_EXT_ZKT != 0);
+    enable_feature(Feature::zihintntl, ext0 & RISCV_HWPROBE_EXT_ZIHINTNTL != 0);
+    enable_feature(Feature::zvbb, ext0 & RISCV_HWPROBE_EXT_ZVBB != 0);
+
+    // Prefer the current key name; if the kernel does not recognize it, fall
+    // back to its predecessor. A kernel recognizing neither simply leaves the
+    // pseudo-feature unset, which is the desired behavior: it is a hint, not
+    // an ISA extension, so there is nothing to degrade gracefully from.
+    let misaligned_perf = [probes[2], probes[3]]
+        .into_iter()
+        .find(|p| p.key != RISCV_HWPROBE_KEY_UNKNOWN)
+        .map(|p| p.value)
+        .unwrap_or(RISCV_HWPROBE_MISALIGNED_UNKNOWN);
+    enable_feature(
+        Feature::fast_unaligned_access,
+        misaligned_perf == RISCV_HWPROBE_MISALIGNED_FAST,
+    );
+
+    true
+}
+
 /// Read list of supported features from the auxiliary vector.
 pub(crate) fn detect_features() -> cache::Initializer {
     let mut value = cache::Initializer::default();
@@ -111,5 +590,28 @@ pub(crate) fn detect_features() -> cache::Initializer {
     // to detect when Rust is used to write Linux kernel modules.
     // These should be more than Auxvec way to detect supervisor features.
 
-    imply_features(value)
+    // `riscv_hwprobe` can see the multi-letter extensions that the auxiliary
+    // vector cannot (Zb*, Zk*, V and friends). Merge its bits into the same
+    // `value` rather than replacing it outright, so that old kernels without
+    // the syscall (`ENOSYS`) still get the single-letter detection above
+    // instead of losing feature detection entirely.
+    detect_features_hwprobe(&mut value);
+
+    let value = imply_features(value);
+
+    // In debug builds, catch a buggy or nonsensical combination from any of
+    // the sources above as early as possible rather than letting it quietly
+    // produce wrong `is_riscv_feature_detected!` answers later. This never
+    // changes `value` itself.
+    if cfg!(debug_assertions) {
+        for violation in super::super::riscv::validate(value) {
+            debug_assert!(
+                false,
+                "inconsistent RISC-V feature detection: feature #{}: {}",
+                violation.feature as u32, violation.requirement
+            );
+        }
+    }
+
+    value
 }
@@ -8,7 +8,8 @@
 //! dependencies due to limited set of features that such mechanisms provide.
 //!
 //! This module provides an OS-independent utility to process such relations
-//! between RISC-V extensions.
+//! between RISC-V extensions, as well as an optional validation pass to
+//! catch inconsistent feature sets reported by a misbehaving provider.
 
 use crate::detect::{Feature, cache};
 
@@ -64,6 +65,32 @@ pub(crate) fn imply_features(mut value: cache::Initializer) -> cache::Initialize
 
         group!(b == zba & zbb & zbs);
 
+        // Vector extension dependency graph. Note that this is deliberately
+        // one-directional: `v` implies its Zve* subsets, but not vice versa,
+        // since e.g. Zve64* omits some 64-bit integer-multiply-high forms
+        // that full `v` provides. This matches how LLVM treats Zve* as
+        // distinct subtargets rather than as mere aliases of `v`.
+        imply!(v => zve64d & zvl128b);
+        imply!(zve64d => zve64f & d);
+        imply!(zve64f => zve32f & zve64x);
+        imply!(zve32f => zve32x & f);
+        imply!(zve64x => zve32x & zvl64b);
+        imply!(zve32x => zvl32b);
+
+        // VLEN doubling chain: a minimum vector length also satisfies every
+        // smaller minimum vector length.
+        imply!(zvl65536b => zvl32768b);
+        imply!(zvl32768b => zvl16384b);
+        imply!(zvl16384b => zvl8192b);
+        imply!(zvl8192b => zvl4096b);
+        imply!(zvl4096b => zvl2048b);
+        imply!(zvl2048b => zvl1024b);
+        imply!(zvl1024b => zvl512b);
+        imply!(zvl512b => zvl256b);
+        imply!(zvl256b => zvl128b);
+        imply!(zvl128b => zvl64b);
+        imply!(zvl64b => zvl32b);
+
         imply!(zhinx => zhinxmin);
         imply!(zdinx | zhinxmin => zfinx);
 
@@ -80,3 +107,147 @@ pub(crate) fn imply_features(mut value: cache::Initializer) -> cache::Initialize
         }
     }
 }
+
+/// A single invariant violated by a detected feature set, as produced by
+/// [`validate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Violation {
+    /// The feature whose presence is inconsistent with the rest of the set.
+    pub(crate) feature: Feature,
+    /// A human-readable description of the unmet requirement or conflict.
+    pub(crate) requirement: &'static str,
+}
+
+/// Check a handful of invariants that a self-consistent, fully-implied
+/// RISC-V feature set must satisfy, in the same spirit as the dependency
+/// resolution tools like LLVM's `RISCVISAInfo` perform.
+///
+/// `imply_features` deliberately performs no such checks: eliminating
+/// inconsistencies is the responsibility of the detection provider, not the
+/// implication engine. This is a separate, non-mutating pass meant to be run
+/// (e.g. behind `debug_assertions`, or via `debug_assert!` on its result)
+/// over an already-converged [`cache::Initializer`] to catch a buggy or
+/// nonsensical provider; it is not a part of normal runtime detection and
+/// never changes the detected set.
+pub(crate) fn validate(value: cache::Initializer) -> impl Iterator<Item = Violation> {
+    // A feature that, if set, requires at least one of a handful of others.
+    //
+    // `imply_features` already forces each of these to hold by construction,
+    // so on the usual post-`imply_features` call path these can only fire if
+    // `imply_features` itself regresses; they exist as a cheap regression
+    // guard for that, not because they catch something `imply_features`
+    // misses today.
+    const REQUIRES: &[(Feature, &[Feature], &str)] = &[
+        (Feature::d, &[Feature::f], "`d` requires `f`"),
+        (Feature::zve32f, &[Feature::f], "`zve32f` requires `f`"),
+        (Feature::zve64f, &[Feature::f], "`zve64f` requires `f`"),
+        (Feature::zve64d, &[Feature::d], "`zve64d` requires `d`"),
+    ];
+    // `rv32e`'s reduced register file (16 integer registers instead of 32)
+    // makes it architecturally incompatible with full `v`, which assumes the
+    // complete `rv32i`/`rv64i` register file is available. The embedded
+    // Zve* subsets are deliberately exempted: the V spec defines them
+    // precisely so vector support can coexist with `rv32e`. `rv32i` and
+    // `rv64i` are listed too: a hart implements exactly one base integer
+    // ISA, so a provider reporting `rv32e` together with another base ISA is
+    // self-contradictory rather than merely missing an extension.
+    //
+    // This list is intentionally short, not incomplete: of all extensions
+    // this crate knows about, `v` and the base ISAs are the only ones
+    // specified as needing more than `rv32e`'s 16-register file. If a future
+    // extension gains a similar requirement, add it here rather than reading
+    // the short length as an oversight.
+    const CONFLICTS_WITH_RV32E: &[Feature] = &[Feature::v, Feature::rv32i, Feature::rv64i];
+
+    let unmet_requirements = REQUIRES
+        .iter()
+        .filter_map(move |&(feature, reqs, requirement)| {
+            let satisfied = reqs.iter().any(|&req| value.test(req as u32));
+            (value.test(feature as u32) && !satisfied).then_some(Violation {
+                feature,
+                requirement,
+            })
+        });
+
+    let rv32e_conflicts = CONFLICTS_WITH_RV32E.iter().filter_map(move |&feature| {
+        (value.test(Feature::rv32e as u32) && value.test(feature as u32)).then_some(Violation {
+            feature,
+            requirement: "not available together with `rv32e`",
+        })
+    });
+
+    unmet_requirements.chain(rv32e_conflicts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with_features(features: &[Feature]) -> cache::Initializer {
+        let mut value = cache::Initializer::default();
+        for &feature in features {
+            value.set(feature as u32);
+        }
+        value
+    }
+
+    #[test]
+    fn imply_features_converges_full_vector_lattice() {
+        let value = imply_features(with_features(&[Feature::v]));
+        for feature in [
+            Feature::zve64d,
+            Feature::zve64f,
+            Feature::zve32f,
+            Feature::zve64x,
+            Feature::zve32x,
+            Feature::d,
+            Feature::f,
+            Feature::zvl128b,
+            Feature::zvl64b,
+            Feature::zvl32b,
+        ] {
+            assert!(value.test(feature as u32), "`v` should imply {feature:?}");
+        }
+    }
+
+    #[test]
+    fn imply_features_vector_implication_is_one_directional() {
+        // The embedded Zve* subsets must not imply full `v` back: they omit
+        // instructions `v` provides, so the implication only goes one way.
+        let value = imply_features(with_features(&[Feature::zve64d]));
+        assert!(!value.test(Feature::v as u32));
+    }
+
+    #[test]
+    fn imply_features_vlen_doubling_chain() {
+        let value = imply_features(with_features(&[Feature::zvl256b]));
+        assert!(value.test(Feature::zvl128b as u32));
+        assert!(value.test(Feature::zvl64b as u32));
+        assert!(value.test(Feature::zvl32b as u32));
+        assert!(!value.test(Feature::zvl512b as u32));
+    }
+
+    #[test]
+    fn validate_flags_d_without_f() {
+        let value = with_features(&[Feature::d]);
+        assert!(validate(value).any(|v| v.feature == Feature::d));
+    }
+
+    #[test]
+    fn validate_accepts_d_with_f() {
+        let value = with_features(&[Feature::d, Feature::f]);
+        assert!(validate(value).next().is_none());
+    }
+
+    #[test]
+    fn validate_flags_rv32e_with_v() {
+        let value = with_features(&[Feature::rv32e, Feature::v]);
+        assert!(validate(value).any(|v| v.feature == Feature::v));
+    }
+
+    #[test]
+    fn validate_flags_rv32e_with_rv32i() {
+        let value = with_features(&[Feature::rv32e, Feature::rv32i]);
+        assert!(validate(value).any(|v| v.feature == Feature::rv32i));
+    }
+}